@@ -109,21 +109,58 @@ impl<'a> PermuteIndex<'a> {
         PermuteIndex { data: index }
     }
 
-    fn generate_swaps(&self) -> Vec<(usize, usize)> {
-        let mut visited = vec![false; self.data.len()];
-        let mut swaps = vec![];
+    /// The length of this index.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this index has length zero.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
 
-        for i in 0..self.data.len() {
+    /// Decomposes this permutation into its disjoint nontrivial cycles, each returned as an
+    /// ordered list of indices (fixed points are omitted).
+    /// Useful for scheduling parallel work (see `try_order_by_index_cycle_parallel_inplace`,
+    /// only available with feature `parallel`), computing permutation order or parity, or
+    /// visualizing how data moves.
+    /// # Example
+    /// ```
+    /// use index_permute::PermuteIndex;
+    /// let index = PermuteIndex::try_new(&[2, 0, 1, 4, 3]).unwrap();
+    /// assert_eq!(index.cycles(), vec![vec![0, 2, 1], vec![3, 4]]);
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let len = self.data.len();
+        let mut visited = vec![false; len];
+        let mut cycles = vec![];
+
+        for i in 0..len {
             if visited[i] || self.data[i] == i {
                 continue;
             }
 
-            let mut x = i;
-
-            while !visited[self.data[x]] {
+            let mut cycle = vec![i];
+            visited[i] = true;
+            let mut x = self.data[i];
+            while x != i {
+                cycle.push(x);
                 visited[x] = true;
                 x = self.data[x];
-                swaps.push((i, x));
+            }
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+
+    fn generate_swaps(&self) -> Vec<(usize, usize)> {
+        let mut swaps = vec![];
+
+        for cycle in self.cycles() {
+            let start = cycle[0];
+            for &n in &cycle[1..] {
+                swaps.push((start, n));
             }
         }
 
@@ -132,6 +169,128 @@ impl<'a> PermuteIndex<'a> {
     }
 }
 
+/// An owned permutation, backed by a `Vec<usize>`.
+/// Unlike [`PermuteIndex`], which borrows its data and is meant to be applied once,
+/// a [`Perm`] can be built up, inverted, and composed before it is ever applied to data.
+/// Use [`Perm::as_index`] to borrow it as a [`PermuteIndex`] when you're ready to permute.
+/// # Example
+/// ```
+/// use index_permute::Perm;
+/// let p = Perm::try_new(vec![2, 0, 1]).unwrap();
+/// let inv = p.inverse();
+/// assert_eq!(inv.compose(&p).unwrap(), Perm::identity(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perm {
+    data: Vec<usize>,
+}
+
+impl Perm {
+    /// Creates a new [`Perm`] from owned index data, validating it via the same rules as
+    /// [`PermuteIndex::try_new`].
+    /// Returns [`PermuteError::InvalidIndex`] if the data is not a valid permutation.
+    pub fn try_new(data: Vec<usize>) -> Result<Self, PermuteError> {
+        if PermuteIndex::check_index(&data) {
+            Ok(Perm { data })
+        } else {
+            Err(PermuteError::InvalidIndex)
+        }
+    }
+
+    /// Creates the identity permutation of length `len`, i.e. `[0, 1, ..., len - 1]`.
+    pub fn identity(len: usize) -> Self {
+        Perm {
+            data: (0..len).collect(),
+        }
+    }
+
+    /// The length of this permutation.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this permutation has length zero.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Borrows this [`Perm`] as a [`PermuteIndex`], for use with functions such as
+    /// [`try_order_by_index_inplace`].
+    pub fn as_index(&self) -> PermuteIndex<'_> {
+        PermuteIndex { data: &self.data }
+    }
+
+    /// Computes the inverse permutation, such that `self.inverse().compose(&self)` is the
+    /// identity: `inv[self.data[i]] = i`.
+    pub fn inverse(&self) -> Self {
+        let mut inv = vec![0; self.data.len()];
+        for (i, &x) in self.data.iter().enumerate() {
+            inv[x] = i;
+        }
+        Perm { data: inv }
+    }
+
+    /// Composes this permutation with `other`, applying `other` first: `(self ∘ other)[i] =
+    /// self.data[other.data[i]]`.
+    /// Returns [`PermuteError::LengthMismatch`] if the two permutations have different lengths.
+    pub fn compose(&self, other: &Perm) -> Result<Self, PermuteError> {
+        if self.data.len() != other.data.len() {
+            return Err(PermuteError::LengthMismatch);
+        }
+        Ok(Perm {
+            data: other.data.iter().map(|&i| self.data[i]).collect(),
+        })
+    }
+
+    /// Raises this permutation to the `k`-th power by repeated composition.
+    pub fn pow(&self, k: u32) -> Self {
+        let mut result = Perm::identity(self.data.len());
+        for _ in 0..k {
+            result = self.compose(&result).expect("same length by construction");
+        }
+        result
+    }
+}
+
+cfg_rand! {
+use rand::Rng;
+
+impl Perm {
+    /// Generates a uniformly random permutation of length `len` using an in-place
+    /// Fisher-Yates shuffle.
+    /// Unlike [`Perm::try_new`], this skips the O(n) validity check, since the shuffle
+    /// produces a valid permutation by construction.
+    /// Only valid when feature `rand` is enabled.
+    pub fn random<R: Rng>(len: usize, rng: &mut R) -> Self {
+        let mut data: Vec<usize> = (0..len).collect();
+        for i in (1..len).rev() {
+            let j = rng.gen_range(0..=i);
+            data.swap(i, j);
+        }
+        Perm { data }
+    }
+}
+
+/// Generates a random permutation of `data.len()` and applies it to `data` in place.
+/// This is an allocation-light shuffle that works on non-[`Clone`] elements, unlike a
+/// shuffle that would collect into a new `Vec`.
+/// Only valid when feature `rand` is enabled.
+/// # Example
+/// ```
+/// # #[cfg(feature = "rand")] {
+/// use index_permute::shuffle_inplace;
+/// let mut data = vec![1, 2, 3, 4, 5];
+/// shuffle_inplace(&mut data, &mut rand::thread_rng());
+/// # }
+/// ```
+pub fn shuffle_inplace<T, R: Rng>(data: &mut [T], rng: &mut R) {
+    let perm = Perm::random(data.len(), rng);
+    if let Err(e) = try_order_by_index_inplace(data, perm.as_index()) {
+        panic!("Failed to shuffle: {}", e);
+    }
+}
+}
+
 /// Reorders the data in place according to the given index.
 /// First create a [`PermuteIndex`], then, it reorders the data in place
 /// # Example
@@ -176,6 +335,91 @@ where
     }
 }
 
+/// A trait for collections that can be reordered by a [`PermuteIndex`].
+/// Implement [`Permute::permute_inplace`] and the out-of-place [`Permute::permuted`] comes for
+/// free, built on top of it via the same cycle-following machinery used by
+/// [`try_order_by_index_inplace`] - so downstream types (e.g. a struct-of-arrays wrapper) only
+/// need to implement the in-place half.
+/// # Example
+/// ```
+/// use index_permute::{Permute, PermuteIndex};
+/// let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+/// let data = vec![10, 20, 30];
+/// assert_eq!(data.permuted(&index), vec![30, 10, 20]);
+/// ```
+pub trait Permute: Sized {
+    /// Consumes `self` and returns it reordered according to `index`: `out[i] =
+    /// self[index[i]]`.
+    /// The default implementation permutes `self` in place and returns it, without making a
+    /// copy; call this on `data.clone()` if you need to keep the original around.
+    fn permuted(mut self, index: &PermuteIndex) -> Self {
+        self.permute_inplace(index.clone());
+        self
+    }
+
+    /// Reorders `self` in place according to `index`.
+    fn permute_inplace(&mut self, index: PermuteIndex);
+}
+
+impl<T> Permute for Vec<T> {
+    fn permute_inplace(&mut self, index: PermuteIndex) {
+        if let Err(e) = try_order_by_index_inplace(self, index) {
+            panic!("Failed to order by index: {}", e);
+        }
+    }
+}
+
+impl<T> Permute for Box<[T]> {
+    fn permute_inplace(&mut self, index: PermuteIndex) {
+        if let Err(e) = try_order_by_index_inplace(self, index) {
+            panic!("Failed to order by index: {}", e);
+        }
+    }
+}
+
+impl<T> Permute for &mut [T] {
+    fn permute_inplace(&mut self, index: PermuteIndex) {
+        if let Err(e) = try_order_by_index_inplace(self, index) {
+            panic!("Failed to order by index: {}", e);
+        }
+    }
+}
+
+/// Implemented for tuples of mutable slices of the same length, so a single [`PermuteIndex`]
+/// can be applied to all of them in lockstep - the columnar / struct-of-arrays case, where a
+/// caller sorts by one key column and must reorder several value columns identically.
+/// The cycle decomposition is computed once and replayed across every slice, instead of
+/// recomputing it per column.
+/// Implementations are generated for tuples via [`impl_permute_multi_for_tuple`].
+pub trait PermuteMulti {
+    /// Applies `index` to every slice in this tuple, in lockstep.
+    /// Returns [`PermuteError::LengthMismatch`] if any slice's length does not match `index`.
+    fn permute_all(self, index: &PermuteIndex) -> Result<(), PermuteError>;
+}
+
+impl_permute_multi_for_tuple!(A:0, B:1);
+impl_permute_multi_for_tuple!(A:0, B:1, C:2);
+impl_permute_multi_for_tuple!(A:0, B:1, C:2, D:3);
+
+/// Applies a single [`PermuteIndex`] to several same-length slices at once, reordering them
+/// identically.
+/// # Example
+/// ```
+/// use index_permute::{PermuteIndex, try_order_by_index_inplace_multi};
+/// let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+/// let mut keys = vec!['c', 'a', 'b'];
+/// let mut values = vec![30, 10, 20];
+/// try_order_by_index_inplace_multi((&mut keys[..], &mut values[..]), index).unwrap();
+/// assert_eq!(keys, vec!['b', 'c', 'a']);
+/// assert_eq!(values, vec![20, 30, 10]);
+/// ```
+pub fn try_order_by_index_inplace_multi<M: PermuteMulti>(
+    slices: M,
+    index: PermuteIndex,
+) -> Result<(), PermuteError> {
+    slices.permute_all(&index)
+}
+
 cfg_parallel! {
 /// Only valid when features `parallel` is enabled.
 /// A parallel version of [`try_order_by_index_inplace`].
@@ -258,6 +502,112 @@ where
     let num_threads = num_cpus::get();
     try_order_by_index_parallel_inplace_with_threads(data, index, num_threads)
 }
+
+/// A raw pointer wrapper used to hand out disjoint, non-overlapping slices of `data` to worker
+/// threads. Each work item only ever touches the indices of its own cycles, which never overlap
+/// with any other work item's indices, so concurrent access through distinct `CyclePtr`s is
+/// sound even though they alias the same allocation.
+struct CyclePtr<T>(*mut T);
+// SAFETY: work items are constructed so that distinct threads only ever touch disjoint indices.
+unsafe impl<T> Send for CyclePtr<T> {}
+
+/// Rotates a single cycle in place: `tmp = data[cycle[0]]`, then `data[cycle[w]] =
+/// data[cycle[w + 1]]` walking the cycle, and finally `data[cycle[last]] = tmp`.
+unsafe fn rotate_cycle<T>(ptr: *mut T, cycle: &[usize]) {
+    if cycle.len() < 2 {
+        return;
+    }
+    unsafe {
+        let tmp = ptr::read(ptr.add(cycle[0]));
+        for w in 0..cycle.len() - 1 {
+            let moved = ptr::read(ptr.add(cycle[w + 1]));
+            ptr::write(ptr.add(cycle[w]), moved);
+        }
+        ptr::write(ptr.add(cycle[cycle.len() - 1]), tmp);
+    }
+}
+
+/// Only valid when feature `parallel` is enabled.
+/// A parallel version of [`try_order_by_index_inplace`] that rotates each disjoint cycle of the
+/// permutation in place, instead of gathering through a full `Vec<T>` scratch buffer like
+/// [`try_order_by_index_parallel_inplace_with_threads`] does. This keeps peak memory at roughly
+/// 1x `data` (one temporary element per cycle) instead of 2x, which matters for large `T`.
+/// # Parameters
+/// - `data`: The data to be permuted.
+/// - `index`: The permutation index, which must be a valid [`PermuteIndex`].
+/// - `num_threads`: The number of threads to use for parallel processing.
+/// # Returns
+/// - `Ok(())` if the operation was successful.
+/// - `Err(PermuteError)` if the index is invalid or the lengths do not match.
+pub fn try_order_by_index_cycle_parallel_inplace_with_threads<T>(
+    data: &mut [T],
+    index: PermuteIndex,
+    num_threads: usize,
+) -> Result<(), PermuteError>
+where
+    T: Send,
+{
+    let len = data.len();
+
+    if len != index.data.len() {
+        return Err(PermuteError::LengthMismatch);
+    }
+
+    if len < 10_000 || num_threads <= 1 {
+        return try_order_by_index_inplace(data, index);
+    }
+
+    let cycles = index.cycles();
+
+    // Greedily distribute cycles into `num_threads` roughly balanced work items by total length.
+    let mut work_items: Vec<Vec<&[usize]>> = vec![Vec::new(); num_threads];
+    let mut work_loads = vec![0usize; num_threads];
+    let mut ordered_cycles: Vec<&Vec<usize>> = cycles.iter().collect();
+    ordered_cycles.sort_by_key(|c| std::cmp::Reverse(c.len()));
+    for cycle in ordered_cycles {
+        let (slot, _) = work_loads
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, &load)| load)
+            .unwrap();
+        work_loads[slot] += cycle.len();
+        work_items[slot].push(cycle.as_slice());
+    }
+
+    let ptr = CyclePtr(data.as_mut_ptr());
+    std::thread::scope(|s| {
+        for item in work_items {
+            if item.is_empty() {
+                continue;
+            }
+            let ptr = CyclePtr(ptr.0);
+            s.spawn(move || {
+                let ptr = ptr;
+                for cycle in &item {
+                    unsafe {
+                        rotate_cycle(ptr.0, cycle);
+                    }
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+/// Same as [`try_order_by_index_cycle_parallel_inplace_with_threads`] but uses the number of
+/// available CPU cores.
+/// Only valid when feature `parallel` is enabled.
+pub fn try_order_by_index_cycle_parallel_inplace<T>(
+    data: &mut [T],
+    index: PermuteIndex,
+) -> Result<(), PermuteError>
+where
+    T: Send,
+{
+    let num_threads = num_cpus::get();
+    try_order_by_index_cycle_parallel_inplace_with_threads(data, index, num_threads)
+}
 }
 #[cfg(test)]
 mod tests {
@@ -269,6 +619,48 @@ mod tests {
         assert_eq!(index.generate_swaps(), vec![(3, 4), (0, 1), (0, 2)]);
     }
 
+    #[test]
+    fn test_cycles() {
+        let index = PermuteIndex::try_new(&[2, 0, 1, 4, 3]).unwrap();
+        assert_eq!(index.cycles(), vec![vec![0, 2, 1], vec![3, 4]]);
+
+        let identity = PermuteIndex::try_new(&[0, 1, 2]).unwrap();
+        assert_eq!(identity.cycles(), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn test_perm_identity_and_inverse() {
+        let p = Perm::try_new(vec![2, 0, 1]).unwrap();
+        let inv = p.inverse();
+        assert_eq!(inv.compose(&p).unwrap(), Perm::identity(3));
+        assert_eq!(p.compose(&inv).unwrap(), Perm::identity(3));
+    }
+
+    #[test]
+    fn test_perm_compose_and_pow() {
+        let p = Perm::try_new(vec![1, 2, 0]).unwrap();
+        assert_eq!(p.pow(0), Perm::identity(3));
+        assert_eq!(p.pow(1), p);
+        assert_eq!(p.pow(3), Perm::identity(3));
+        assert_eq!(p.compose(&p).unwrap(), p.pow(2));
+    }
+
+    #[test]
+    fn test_perm_invalid_index() {
+        assert!(matches!(
+            Perm::try_new(vec![0, 0]),
+            Err(PermuteError::InvalidIndex)
+        ));
+    }
+
+    #[test]
+    fn test_perm_as_index() {
+        let p = Perm::try_new(vec![2, 0, 1]).unwrap();
+        let mut data = vec![10, 20, 30];
+        assert!(try_order_by_index_inplace(&mut data, p.as_index()).is_ok());
+        assert_eq!(data, vec![30, 10, 20]);
+    }
+
     #[test]
     fn test_permute_index() {
         let _ = PermuteIndex::try_new(&[0usize, 2, 1]);
@@ -277,6 +669,57 @@ mod tests {
         let _ = PermuteIndex::try_new(&[0, 1, 2][..]);
     }
 
+    #[test]
+    fn test_permute_trait_vec() {
+        let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+        let data = vec![10, 20, 30];
+        assert_eq!(data.clone().permuted(&index), vec![30, 10, 20]);
+
+        let mut data = data;
+        data.permute_inplace(index);
+        assert_eq!(data, vec![30, 10, 20]);
+    }
+
+    #[test]
+    fn test_permute_trait_slice() {
+        let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+        let mut data = vec![10, 20, 30];
+        data.as_mut_slice().permute_inplace(index);
+        assert_eq!(data, vec![30, 10, 20]);
+    }
+
+    #[test]
+    fn test_permute_trait_box_slice() {
+        let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+        let data: Box<[i32]> = vec![10, 20, 30].into_boxed_slice();
+        assert_eq!(data.clone().permuted(&index), vec![30, 10, 20].into());
+
+        let mut data = data;
+        data.permute_inplace(index);
+        assert_eq!(data, vec![30, 10, 20].into());
+    }
+
+    #[test]
+    fn test_order_by_index_inplace_multi() {
+        let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+        let mut keys = vec!['c', 'a', 'b'];
+        let mut values = vec![30, 10, 20];
+        assert!(try_order_by_index_inplace_multi((&mut keys[..], &mut values[..]), index).is_ok());
+        assert_eq!(keys, vec!['b', 'c', 'a']);
+        assert_eq!(values, vec![20, 30, 10]);
+    }
+
+    #[test]
+    fn test_order_by_index_inplace_multi_length_mismatch() {
+        let index = PermuteIndex::try_new(&[2, 0, 1]).unwrap();
+        let mut keys = ['c', 'a', 'b'];
+        let mut values = [30, 10];
+        assert!(matches!(
+            try_order_by_index_inplace_multi((&mut keys[..], &mut values[..]), index),
+            Err(PermuteError::LengthMismatch)
+        ));
+    }
+
     #[test]
     fn test_permute_order() {
         let mut data = vec![10, 20, 30];
@@ -311,6 +754,25 @@ mod tests {
         assert_eq!(data[2].value, 2);
     }
 
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_perm_random_is_valid() {
+        let mut rng = rand::thread_rng();
+        let perm = Perm::random(100, &mut rng);
+        assert!(PermuteIndex::check_index(&perm.data));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_shuffle_inplace() {
+        let mut rng = rand::thread_rng();
+        let mut data = (0..100).collect::<Vec<_>>();
+        shuffle_inplace(&mut data, &mut rng);
+        let mut sorted = data.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+    }
+
     #[test]
     #[cfg(feature = "parallel")]
     fn test_order_by_index_parallel() {
@@ -350,4 +812,41 @@ mod tests {
             assert_eq!(data[i].value, test_size - 1 - i);
         }
     }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_order_by_index_cycle_parallel() {
+        let mut data = (0..1000).collect::<Vec<_>>();
+        let index_vec = (0..1000).rev().collect::<Vec<_>>();
+        let index = PermuteIndex::try_new(&index_vec).unwrap();
+        assert!(try_order_by_index_cycle_parallel_inplace(&mut data, index).is_ok());
+        assert_eq!(data, (0..1000).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_order_by_index_cycle_parallel_drop() {
+        struct DropTest {
+            value: usize,
+        }
+        impl Drop for DropTest {
+            fn drop(&mut self) {
+                print!(".",);
+            }
+        }
+        let test_size = 10001;
+        let mut data = (0..test_size)
+            .map(|i| DropTest { value: i })
+            .collect::<Vec<_>>();
+        let index_vec = (0..test_size).rev().collect::<Vec<_>>();
+        let index = PermuteIndex::try_new(&index_vec).unwrap();
+
+        // now, there should be no drop
+        try_order_by_index_cycle_parallel_inplace_with_threads(&mut data, index, 4).unwrap();
+        println!("no drop should happen here");
+
+        for i in 0..test_size {
+            assert_eq!(data[i].value, test_size - 1 - i);
+        }
+    }
 }
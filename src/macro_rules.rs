@@ -8,4 +8,42 @@ macro_rules! cfg_parallel {
             $item
         )*
     }
-}
\ No newline at end of file
+}
+
+#[macro_export]
+/// A macro to conditionally compile items based on the `rand` feature.
+macro_rules! cfg_rand {
+    ($($item:item)*) => {
+        $(
+            #[cfg(feature = "rand")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+            $item
+        )*
+    }
+}
+
+#[macro_export]
+/// Implements [`PermuteMulti`](crate::PermuteMulti) for a tuple of mutable slices, so the same
+/// cycle decomposition can be replayed across every slice in lockstep.
+macro_rules! impl_permute_multi_for_tuple {
+    ($($t:ident : $idx:tt),+ $(,)?) => {
+        impl<$($t),+> $crate::PermuteMulti for ($(&mut [$t]),+,) {
+            fn permute_all(self, index: &$crate::PermuteIndex) -> Result<(), $crate::PermuteError> {
+                let len = index.len();
+                $(
+                    if self.$idx.len() != len {
+                        return Err($crate::PermuteError::LengthMismatch);
+                    }
+                )+
+
+                for (a, b) in index.generate_swaps() {
+                    $(
+                        self.$idx.swap(a, b);
+                    )+
+                }
+
+                Ok(())
+            }
+        }
+    };
+}